@@ -11,6 +11,13 @@ pub enum DataKey {
     BountyCounter,
     CompanyBounties(Address),
     DeveloperBounties(Address),
+    Arbitrator,
+    Milestones(u64),
+    Strikes(Address),
+    PaymentHistory(Address),
+    StatusIndex(BountyStatus),
+    Applications(u64),
+    Admin,
 }
 
 #[derive(Clone)]
@@ -34,6 +41,35 @@ pub enum BountyStatus {
     Cancelled,
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct Milestone {
+    pub description: String,
+    pub amount: i128,
+    pub submitted: bool,
+    pub released: bool,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Application {
+    pub developer: Address,
+    pub proposed_amount: i128,
+    pub cover_note: String,
+    pub rating_snapshot: u32,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct PaymentRecord {
+    pub bounty_id: u64,
+    pub counterparty: Address,
+    pub amount: i128,
+    pub token: Address,
+    pub timestamp: u64,
+    pub kind: String,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct Bounty {
@@ -48,6 +84,7 @@ pub struct Bounty {
     pub assigned_developer: Option<Address>,
     pub created_at: u64,
     pub deadline: u64,
+    pub stake_amount: i128,
 }
 
 #[contract]
@@ -55,7 +92,76 @@ pub struct FreelanceBountyPlatform;
 
 #[contractimpl]
 impl FreelanceBountyPlatform {
-    
+
+    /// Append a payment record to a party's audit trail
+    fn record_payment(
+        env: &Env,
+        who: &Address,
+        bounty_id: u64,
+        counterparty: &Address,
+        amount: i128,
+        token: &Address,
+        kind: String,
+    ) {
+        let record = PaymentRecord {
+            bounty_id,
+            counterparty: counterparty.clone(),
+            amount,
+            token: token.clone(),
+            timestamp: env.ledger().timestamp(),
+            kind,
+        };
+        let mut history: Vec<PaymentRecord> = env.storage()
+            .instance()
+            .get(&DataKey::PaymentHistory(who.clone()))
+            .unwrap_or(Vec::new(env));
+        history.push_back(record);
+        env.storage().instance().set(&DataKey::PaymentHistory(who.clone()), &history);
+    }
+
+    /// Enumerate every `BountyStatus` variant
+    fn all_statuses(env: &Env) -> Vec<BountyStatus> {
+        let mut statuses = Vec::new(env);
+        statuses.push_back(BountyStatus::Open);
+        statuses.push_back(BountyStatus::Assigned);
+        statuses.push_back(BountyStatus::Submitted);
+        statuses.push_back(BountyStatus::Completed);
+        statuses.push_back(BountyStatus::Disputed);
+        statuses.push_back(BountyStatus::Cancelled);
+        statuses
+    }
+
+    /// Add a bounty to the index for `status`
+    fn index_add(env: &Env, status: BountyStatus, bounty_id: u64) {
+        let mut ids: Vec<u64> = env.storage()
+            .instance()
+            .get(&DataKey::StatusIndex(status.clone()))
+            .unwrap_or(Vec::new(env));
+        ids.push_back(bounty_id);
+        env.storage().instance().set(&DataKey::StatusIndex(status), &ids);
+    }
+
+    /// Remove a bounty from the index for `status`
+    fn index_remove(env: &Env, status: BountyStatus, bounty_id: u64) {
+        let ids: Vec<u64> = env.storage()
+            .instance()
+            .get(&DataKey::StatusIndex(status.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut kept = Vec::new(env);
+        for id in ids.iter() {
+            if id != bounty_id {
+                kept.push_back(id);
+            }
+        }
+        env.storage().instance().set(&DataKey::StatusIndex(status), &kept);
+    }
+
+    /// Move a bounty between status indexes as it transitions
+    fn index_transition(env: &Env, bounty_id: u64, from: BountyStatus, to: BountyStatus) {
+        Self::index_remove(env, from, bounty_id);
+        Self::index_add(env, to, bounty_id);
+    }
+
     /// Register or update developer profile
     pub fn register_developer(
         env: Env,
@@ -104,13 +210,14 @@ impl FreelanceBountyPlatform {
         payment_amount: i128,
         payment_token: Address,
         deadline: u64,
+        milestones: Vec<Milestone>,
     ) -> u64 {
         company.require_auth();
-        
+
         // Transfer tokens to contract (escrow)
         let token_client = token::Client::new(&env, &payment_token);
         token_client.transfer(&company, &env.current_contract_address(), &payment_amount);
-        
+
         // Get or initialize bounty counter
         let bounty_id: u64 = env.storage()
             .instance()
@@ -126,48 +233,88 @@ impl FreelanceBountyPlatform {
             description,
             required_skills,
             payment_amount,
-            payment_token,
+            payment_token: payment_token.clone(),
             status: BountyStatus::Open,
             assigned_developer: None,
             created_at: env.ledger().timestamp(),
             deadline,
+            stake_amount: 0,
         };
         
         env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
-        
+        Self::index_add(&env, BountyStatus::Open, bounty_id);
+
+        // Optional milestones: amounts must sum to the escrowed payment_amount
+        if !milestones.is_empty() {
+            let mut total: i128 = 0;
+            let mut normalized = Vec::new(&env);
+            for milestone in milestones.iter() {
+                total += milestone.amount;
+                normalized.push_back(Milestone {
+                    description: milestone.description,
+                    amount: milestone.amount,
+                    submitted: false,
+                    released: false,
+                });
+            }
+            assert!(total == payment_amount, "Milestone amounts must sum to payment_amount");
+            env.storage().instance().set(&DataKey::Milestones(bounty_id), &normalized);
+        }
+
         // Track company's bounties
         let mut company_bounties: Vec<u64> = env.storage()
             .instance()
             .get(&DataKey::CompanyBounties(company.clone()))
             .unwrap_or(Vec::new(&env));
         company_bounties.push_back(bounty_id);
-        env.storage().instance().set(&DataKey::CompanyBounties(company), &company_bounties);
-        
+        env.storage().instance().set(&DataKey::CompanyBounties(company.clone()), &company_bounties);
+
+        // Emit event and record the escrow deposit in the company's ledger
+        env.events().publish(
+            (String::from_str(&env, "bounty"), String::from_str(&env, "created")),
+            (bounty_id, company.clone(), payment_amount),
+        );
+        Self::record_payment(
+            &env,
+            &company,
+            bounty_id,
+            &env.current_contract_address(),
+            payment_amount,
+            &payment_token,
+            String::from_str(&env, "created"),
+        );
+
         bounty_id
     }
     
-    /// Developer applies/gets assigned to bounty
-    pub fn assign_bounty(env: Env, bounty_id: u64, developer: Address) {
+    /// Developer applies/gets assigned to bounty, locking a commitment stake
+    pub fn assign_bounty(env: Env, bounty_id: u64, developer: Address, stake_amount: i128) {
         developer.require_auth();
-        
+
         let mut bounty: Bounty = env.storage()
             .instance()
             .get(&DataKey::Bounty(bounty_id))
             .expect("Bounty not found");
-        
+
         // Check bounty is open
         assert!(bounty.status == BountyStatus::Open, "Bounty is not open");
-        
+
         // Check developer is registered
         let _profile: DeveloperProfile = env.storage()
             .instance()
             .get(&DataKey::Developer(developer.clone()))
             .expect("Developer not registered");
-        
+
+        // Lock the developer's commitment stake in the bounty's token
+        let token_client = token::Client::new(&env, &bounty.payment_token);
+        token_client.transfer(&developer, &env.current_contract_address(), &stake_amount);
+
         bounty.status = BountyStatus::Assigned;
         bounty.assigned_developer = Some(developer.clone());
-        
+        bounty.stake_amount = stake_amount;
+
         env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+        Self::index_transition(&env, bounty_id, BountyStatus::Open, BountyStatus::Assigned);
         
         // Track developer's bounties
         let mut dev_bounties: Vec<u64> = env.storage()
@@ -177,7 +324,100 @@ impl FreelanceBountyPlatform {
         dev_bounties.push_back(bounty_id);
         env.storage().instance().set(&DataKey::DeveloperBounties(developer), &dev_bounties);
     }
-    
+
+    /// Developer bids on an open bounty with a proposal
+    pub fn apply_to_bounty(
+        env: Env,
+        bounty_id: u64,
+        developer: Address,
+        proposed_amount: i128,
+        cover_note: String,
+    ) {
+        developer.require_auth();
+
+        let bounty: Bounty = env.storage()
+            .instance()
+            .get(&DataKey::Bounty(bounty_id))
+            .expect("Bounty not found");
+
+        assert!(bounty.status == BountyStatus::Open, "Bounty is not open");
+
+        // Developer must be registered; snapshot their current rating
+        let profile: DeveloperProfile = env.storage()
+            .instance()
+            .get(&DataKey::Developer(developer.clone()))
+            .expect("Developer not registered");
+
+        let application = Application {
+            developer: developer.clone(),
+            proposed_amount,
+            cover_note,
+            rating_snapshot: profile.rating,
+        };
+
+        let mut applications: Vec<Application> = env.storage()
+            .instance()
+            .get(&DataKey::Applications(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        applications.push_back(application);
+        env.storage().instance().set(&DataKey::Applications(bounty_id), &applications);
+    }
+
+    /// Get all applications for a bounty
+    pub fn get_applications(env: Env, bounty_id: u64) -> Vec<Application> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Applications(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Company selects an applicant and assigns them the bounty
+    pub fn select_developer(
+        env: Env,
+        bounty_id: u64,
+        company: Address,
+        developer: Address,
+        stake_amount: i128,
+    ) {
+        company.require_auth();
+        // The developer also signs so their commitment stake can be locked.
+        developer.require_auth();
+
+        let mut bounty: Bounty = env.storage()
+            .instance()
+            .get(&DataKey::Bounty(bounty_id))
+            .expect("Bounty not found");
+
+        assert!(bounty.company == company, "Not authorized");
+        assert!(bounty.status == BountyStatus::Open, "Bounty is not open");
+
+        // Verify the developer actually applied
+        let applications: Vec<Application> = env.storage()
+            .instance()
+            .get(&DataKey::Applications(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let applied = applications.iter().any(|app| app.developer == developer);
+        assert!(applied, "Developer did not apply to this bounty");
+
+        // Lock the developer's commitment stake, as in the self-service path
+        let token_client = token::Client::new(&env, &bounty.payment_token);
+        token_client.transfer(&developer, &env.current_contract_address(), &stake_amount);
+
+        bounty.status = BountyStatus::Assigned;
+        bounty.assigned_developer = Some(developer.clone());
+        bounty.stake_amount = stake_amount;
+        env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+        Self::index_transition(&env, bounty_id, BountyStatus::Open, BountyStatus::Assigned);
+
+        // Track developer's bounties
+        let mut dev_bounties: Vec<u64> = env.storage()
+            .instance()
+            .get(&DataKey::DeveloperBounties(developer.clone()))
+            .unwrap_or(Vec::new(&env));
+        dev_bounties.push_back(bounty_id);
+        env.storage().instance().set(&DataKey::DeveloperBounties(developer), &dev_bounties);
+    }
+
     /// Developer submits work
     pub fn submit_work(env: Env, bounty_id: u64, developer: Address) {
         developer.require_auth();
@@ -192,16 +432,23 @@ impl FreelanceBountyPlatform {
             Some(addr) if addr == &developer => {},
             _ => panic!("Developer not assigned to this bounty"),
         }
-        
+
         assert!(
             bounty.status == BountyStatus::Assigned,
             "Bounty is not in assigned state"
         );
-        
+
+        // Milestone bounties are paid out exclusively through the milestone flow
+        assert!(
+            !env.storage().instance().has(&DataKey::Milestones(bounty_id)),
+            "Use the milestone flow for this bounty"
+        );
+
         bounty.status = BountyStatus::Submitted;
         env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+        Self::index_transition(&env, bounty_id, BountyStatus::Assigned, BountyStatus::Submitted);
     }
-    
+
     /// Company approves work and releases payment
     pub fn approve_and_release(env: Env, bounty_id: u64, company: Address) {
         company.require_auth();
@@ -218,20 +465,34 @@ impl FreelanceBountyPlatform {
             bounty.status == BountyStatus::Submitted,
             "Work not submitted yet"
         );
-        
+
+        // Milestone bounties are paid out exclusively through the milestone flow
+        assert!(
+            !env.storage().instance().has(&DataKey::Milestones(bounty_id)),
+            "Use the milestone flow for this bounty"
+        );
+
         let developer = bounty.assigned_developer.as_ref().expect("No developer assigned");
         
-        // Release payment from escrow
+        // Release payment from escrow, plus refund the developer's commitment stake
         let token_client = token::Client::new(&env, &bounty.payment_token);
         token_client.transfer(
             &env.current_contract_address(),
             developer,
             &bounty.payment_amount,
         );
-        
+        if bounty.stake_amount > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                developer,
+                &bounty.stake_amount,
+            );
+        }
+
         bounty.status = BountyStatus::Completed;
         env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
-        
+        Self::index_transition(&env, bounty_id, BountyStatus::Submitted, BountyStatus::Completed);
+
         // Update developer stats
         let mut dev_profile: DeveloperProfile = env.storage()
             .instance()
@@ -240,8 +501,129 @@ impl FreelanceBountyPlatform {
         
         dev_profile.completed_bounties += 1;
         env.storage().instance().set(&DataKey::Developer(developer.clone()), &dev_profile);
+
+        // Emit event and record the payout for both parties
+        env.events().publish(
+            (String::from_str(&env, "bounty"), String::from_str(&env, "paid")),
+            (bounty_id, developer.clone(), bounty.payment_amount),
+        );
+        Self::record_payment(
+            &env, developer, bounty_id, &bounty.company,
+            bounty.payment_amount, &bounty.payment_token, String::from_str(&env, "paid"),
+        );
+        Self::record_payment(
+            &env, &bounty.company, bounty_id, developer,
+            bounty.payment_amount, &bounty.payment_token, String::from_str(&env, "paid"),
+        );
     }
-    
+
+    /// Developer submits a single milestone for review
+    pub fn submit_milestone(env: Env, bounty_id: u64, index: u32, developer: Address) {
+        developer.require_auth();
+
+        let bounty: Bounty = env.storage()
+            .instance()
+            .get(&DataKey::Bounty(bounty_id))
+            .expect("Bounty not found");
+
+        // Verify developer is assigned
+        match &bounty.assigned_developer {
+            Some(addr) if addr == &developer => {},
+            _ => panic!("Developer not assigned to this bounty"),
+        }
+
+        assert!(
+            bounty.status == BountyStatus::Assigned,
+            "Bounty is not in assigned state"
+        );
+
+        let mut milestones: Vec<Milestone> = env.storage()
+            .instance()
+            .get(&DataKey::Milestones(bounty_id))
+            .expect("Bounty has no milestones");
+
+        let mut milestone = milestones.get(index).expect("Milestone not found");
+        assert!(!milestone.released, "Milestone already released");
+
+        milestone.submitted = true;
+        milestones.set(index, milestone);
+        env.storage().instance().set(&DataKey::Milestones(bounty_id), &milestones);
+    }
+
+    /// Company approves a milestone and releases its share of escrow
+    pub fn approve_milestone(env: Env, bounty_id: u64, index: u32, company: Address) {
+        company.require_auth();
+
+        let mut bounty: Bounty = env.storage()
+            .instance()
+            .get(&DataKey::Bounty(bounty_id))
+            .expect("Bounty not found");
+
+        assert!(bounty.company == company, "Not authorized");
+
+        assert!(
+            bounty.status == BountyStatus::Assigned,
+            "Bounty is not in assigned state"
+        );
+
+        let developer = bounty.assigned_developer.as_ref().expect("No developer assigned");
+
+        let mut milestones: Vec<Milestone> = env.storage()
+            .instance()
+            .get(&DataKey::Milestones(bounty_id))
+            .expect("Bounty has no milestones");
+
+        let mut milestone = milestones.get(index).expect("Milestone not found");
+        assert!(milestone.submitted, "Milestone not submitted");
+        assert!(!milestone.released, "Milestone already released");
+
+        // Release only this milestone's amount from escrow
+        let token_client = token::Client::new(&env, &bounty.payment_token);
+        token_client.transfer(&env.current_contract_address(), developer, &milestone.amount);
+
+        // Emit event and record the milestone payout for both parties
+        env.events().publish(
+            (String::from_str(&env, "bounty"), String::from_str(&env, "paid")),
+            (bounty_id, developer.clone(), milestone.amount),
+        );
+        Self::record_payment(
+            &env, developer, bounty_id, &bounty.company,
+            milestone.amount, &bounty.payment_token, String::from_str(&env, "paid"),
+        );
+        Self::record_payment(
+            &env, &bounty.company, bounty_id, developer,
+            milestone.amount, &bounty.payment_token, String::from_str(&env, "paid"),
+        );
+
+        milestone.released = true;
+        milestones.set(index, milestone);
+        env.storage().instance().set(&DataKey::Milestones(bounty_id), &milestones);
+
+        // When every milestone is released the bounty is complete
+        let all_released = milestones.iter().all(|m| m.released);
+        if all_released {
+            // Refund the developer's commitment stake on successful completion
+            if bounty.stake_amount > 0 {
+                token_client.transfer(
+                    &env.current_contract_address(),
+                    developer,
+                    &bounty.stake_amount,
+                );
+            }
+
+            bounty.status = BountyStatus::Completed;
+            env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+            Self::index_transition(&env, bounty_id, BountyStatus::Assigned, BountyStatus::Completed);
+
+            let mut dev_profile: DeveloperProfile = env.storage()
+                .instance()
+                .get(&DataKey::Developer(developer.clone()))
+                .expect("Developer not found");
+            dev_profile.completed_bounties += 1;
+            env.storage().instance().set(&DataKey::Developer(developer.clone()), &dev_profile);
+        }
+    }
+
     /// Dispute a bounty (can be called by company or developer)
     pub fn dispute_bounty(env: Env, bounty_id: u64, caller: Address) {
         caller.require_auth();
@@ -256,11 +638,133 @@ impl FreelanceBountyPlatform {
             bounty.assigned_developer.as_ref() == Some(&caller);
         
         assert!(is_authorized, "Not authorized");
-        
+
+        // Only live work can be disputed; completed/cancelled bounties are final
+        assert!(
+            bounty.status == BountyStatus::Assigned || bounty.status == BountyStatus::Submitted,
+            "Bounty cannot be disputed in this state"
+        );
+
+        let old_status = bounty.status.clone();
         bounty.status = BountyStatus::Disputed;
         env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+        Self::index_transition(&env, bounty_id, old_status, BountyStatus::Disputed);
     }
-    
+
+    /// Initialize the contract with a platform admin (can only be set once)
+    pub fn initialize(env: Env, admin: Address) {
+        admin.require_auth();
+
+        assert!(
+            !env.storage().instance().has(&DataKey::Admin),
+            "Already initialized"
+        );
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Register the platform arbitrator (admin only, can only be set once)
+    pub fn set_arbitrator(env: Env, arbitrator: Address) {
+        let admin: Address = env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Not initialized");
+        admin.require_auth();
+
+        assert!(
+            !env.storage().instance().has(&DataKey::Arbitrator),
+            "Arbitrator already set"
+        );
+
+        env.storage().instance().set(&DataKey::Arbitrator, &arbitrator);
+    }
+
+    /// Get the platform arbitrator
+    pub fn get_arbitrator(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Arbitrator)
+    }
+
+    /// Arbitrator resolves a disputed bounty, splitting escrow by basis points
+    pub fn resolve_dispute(env: Env, bounty_id: u64, arbitrator: Address, developer_bps: u32) {
+        arbitrator.require_auth();
+
+        // Only the registered arbitrator may resolve disputes
+        let registered: Address = env.storage()
+            .instance()
+            .get(&DataKey::Arbitrator)
+            .expect("Arbitrator not set");
+        assert!(registered == arbitrator, "Not authorized");
+
+        assert!(developer_bps <= 10_000, "developer_bps out of range");
+
+        let mut bounty: Bounty = env.storage()
+            .instance()
+            .get(&DataKey::Bounty(bounty_id))
+            .expect("Bounty not found");
+
+        assert!(bounty.status == BountyStatus::Disputed, "Bounty is not disputed");
+
+        let developer = bounty.assigned_developer.as_ref().expect("No developer assigned");
+
+        // Split only the escrow that remains after any released milestones
+        let mut released: i128 = 0;
+        if let Some(milestones) = env.storage()
+            .instance()
+            .get::<DataKey, Vec<Milestone>>(&DataKey::Milestones(bounty_id))
+        {
+            for milestone in milestones.iter() {
+                if milestone.released {
+                    released += milestone.amount;
+                }
+            }
+        }
+        let remainder = bounty.payment_amount - released;
+
+        let dev_share = remainder * (developer_bps as i128) / 10_000;
+        let company_share = remainder - dev_share;
+
+        let token_client = token::Client::new(&env, &bounty.payment_token);
+        if dev_share > 0 {
+            token_client.transfer(&env.current_contract_address(), developer, &dev_share);
+            Self::record_payment(
+                &env, developer, bounty_id, &bounty.company,
+                dev_share, &bounty.payment_token, String::from_str(&env, "paid"),
+            );
+        }
+        if company_share > 0 {
+            token_client.transfer(&env.current_contract_address(), &bounty.company, &company_share);
+            Self::record_payment(
+                &env, &bounty.company, bounty_id, developer,
+                company_share, &bounty.payment_token, String::from_str(&env, "refunded"),
+            );
+        }
+
+        // Emit event recording how the disputed escrow was split
+        env.events().publish(
+            (String::from_str(&env, "bounty"), String::from_str(&env, "resolved")),
+            (bounty_id, dev_share, company_share),
+        );
+
+        // Return the developer's commitment stake regardless of the split
+        if bounty.stake_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), developer, &bounty.stake_amount);
+        }
+
+        // Credit the developer only when they actually received a share
+        if dev_share > 0 {
+            let mut dev_profile: DeveloperProfile = env.storage()
+                .instance()
+                .get(&DataKey::Developer(developer.clone()))
+                .expect("Developer not found");
+            dev_profile.completed_bounties += 1;
+            env.storage().instance().set(&DataKey::Developer(developer.clone()), &dev_profile);
+        }
+
+        bounty.status = BountyStatus::Completed;
+        env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+        Self::index_transition(&env, bounty_id, BountyStatus::Disputed, BountyStatus::Completed);
+    }
+
     /// Cancel bounty and refund (only if not assigned)
     pub fn cancel_bounty(env: Env, bounty_id: u64, company: Address) {
         company.require_auth();
@@ -287,13 +791,166 @@ impl FreelanceBountyPlatform {
         
         bounty.status = BountyStatus::Cancelled;
         env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+        Self::index_transition(&env, bounty_id, BountyStatus::Open, BountyStatus::Cancelled);
+
+        // Emit event and record the refund in the company's ledger
+        env.events().publish(
+            (String::from_str(&env, "bounty"), String::from_str(&env, "refunded")),
+            (bounty_id, company.clone(), bounty.payment_amount),
+        );
+        Self::record_payment(
+            &env, &company, bounty_id, &env.current_contract_address(),
+            bounty.payment_amount, &bounty.payment_token, String::from_str(&env, "refunded"),
+        );
     }
-    
+
+    /// Reclaim escrow from an assigned developer who missed the deadline,
+    /// slashing their commitment stake and recording a strike.
+    pub fn reclaim_expired_bounty(env: Env, bounty_id: u64, company: Address) {
+        company.require_auth();
+
+        let mut bounty: Bounty = env.storage()
+            .instance()
+            .get(&DataKey::Bounty(bounty_id))
+            .expect("Bounty not found");
+
+        assert!(bounty.company == company, "Not authorized");
+
+        assert!(
+            bounty.status == BountyStatus::Assigned,
+            "Bounty is not in assigned state"
+        );
+
+        assert!(
+            env.ledger().timestamp() > bounty.deadline,
+            "Bounty has not expired"
+        );
+
+        let developer = bounty.assigned_developer.clone().expect("No developer assigned");
+
+        // Refund the escrow that remains after any released milestones and
+        // forfeit the developer's stake to the company
+        let mut released: i128 = 0;
+        if let Some(milestones) = env.storage()
+            .instance()
+            .get::<DataKey, Vec<Milestone>>(&DataKey::Milestones(bounty_id))
+        {
+            for milestone in milestones.iter() {
+                if milestone.released {
+                    released += milestone.amount;
+                }
+            }
+        }
+        let refund = bounty.payment_amount - released;
+
+        let token_client = token::Client::new(&env, &bounty.payment_token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &company,
+            &refund,
+        );
+        if bounty.stake_amount > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &company,
+                &bounty.stake_amount,
+            );
+        }
+
+        // Emit event and record the refund plus forfeited stake for the company
+        let reclaimed = refund + bounty.stake_amount;
+        env.events().publish(
+            (String::from_str(&env, "bounty"), String::from_str(&env, "slashed")),
+            (bounty_id, developer.clone(), reclaimed),
+        );
+        Self::record_payment(
+            &env, &company, bounty_id, &developer,
+            reclaimed, &bounty.payment_token, String::from_str(&env, "refunded"),
+        );
+
+        // Record a strike against the no-show developer
+        let strikes: u32 = env.storage()
+            .instance()
+            .get(&DataKey::Strikes(developer.clone()))
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::Strikes(developer.clone()), &(strikes + 1));
+
+        // Close the bounty out: its escrow has been refunded, so it cannot be
+        // reopened without re-funding.
+        bounty.status = BountyStatus::Cancelled;
+        bounty.stake_amount = 0;
+        env.storage().instance().set(&DataKey::Bounty(bounty_id), &bounty);
+        Self::index_transition(&env, bounty_id, BountyStatus::Assigned, BountyStatus::Cancelled);
+    }
+
+    /// Get the number of strikes recorded against a developer
+    pub fn get_strikes(env: Env, developer: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Strikes(developer))
+            .unwrap_or(0)
+    }
+
     /// Get bounty details
     pub fn get_bounty(env: Env, bounty_id: u64) -> Option<Bounty> {
         env.storage().instance().get(&DataKey::Bounty(bounty_id))
     }
     
+    /// Get every bounty id across all statuses
+    pub fn get_all_bounties(env: Env) -> Vec<u64> {
+        let mut all = Vec::new(&env);
+        for status in Self::all_statuses(&env).iter() {
+            let ids: Vec<u64> = env.storage()
+                .instance()
+                .get(&DataKey::StatusIndex(status))
+                .unwrap_or(Vec::new(&env));
+            for id in ids.iter() {
+                all.push_back(id);
+            }
+        }
+        all
+    }
+
+    /// Get all bounty ids currently in the given status
+    pub fn get_bounties_by_status(env: Env, status: BountyStatus) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::StatusIndex(status))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get all currently open bounties
+    pub fn get_open_bounties(env: Env) -> Vec<Bounty> {
+        let ids: Vec<u64> = env.storage()
+            .instance()
+            .get(&DataKey::StatusIndex(BountyStatus::Open))
+            .unwrap_or(Vec::new(&env));
+        let mut bounties = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(bounty) = env.storage().instance().get::<DataKey, Bounty>(&DataKey::Bounty(id)) {
+                bounties.push_back(bounty);
+            }
+        }
+        bounties
+    }
+
+    /// Get open bounty ids whose required skills contain the given skill
+    pub fn get_bounties_by_skill(env: Env, skill: String) -> Vec<u64> {
+        let ids: Vec<u64> = env.storage()
+            .instance()
+            .get(&DataKey::StatusIndex(BountyStatus::Open))
+            .unwrap_or(Vec::new(&env));
+        let mut matching = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(bounty) = env.storage().instance().get::<DataKey, Bounty>(&DataKey::Bounty(id)) {
+                if bounty.required_skills.contains(&skill) {
+                    matching.push_back(id);
+                }
+            }
+        }
+        matching
+    }
+
     /// Get company's bounties
     pub fn get_company_bounties(env: Env, company: Address) -> Vec<u64> {
         env.storage()
@@ -309,6 +966,14 @@ impl FreelanceBountyPlatform {
             .get(&DataKey::DeveloperBounties(developer))
             .unwrap_or(Vec::new(&env))
     }
+
+    /// Get a party's payment history (both companies and developers)
+    pub fn get_payment_history(env: Env, who: Address) -> Vec<PaymentRecord> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PaymentHistory(who))
+            .unwrap_or(Vec::new(&env))
+    }
     
     /// Rate developer (called by company after completion)
     pub fn rate_developer(
@@ -353,14 +1018,150 @@ impl FreelanceBountyPlatform {
 #[cfg(test)]
 mod test {
     use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Env};
 
     #[test]
     fn test_bounty_status_comparison() {
         let status1 = BountyStatus::Open;
         let status2 = BountyStatus::Open;
         let status3 = BountyStatus::Assigned;
-        
+
         assert!(status1 == status2);
         assert!(status1 != status3);
     }
+
+    // Set up an issued token and return its address plus an admin client for minting.
+    fn setup_token(env: &Env) -> (Address, token::StellarAssetClient) {
+        let issuer = Address::generate(env);
+        let contract = env.register_stellar_asset_contract_v2(issuer);
+        let addr = contract.address();
+        (addr.clone(), token::StellarAssetClient::new(env, &addr))
+    }
+
+    fn register_client(env: &Env) -> FreelanceBountyPlatformClient {
+        let contract_id = env.register_contract(None, FreelanceBountyPlatform);
+        FreelanceBountyPlatformClient::new(env, &contract_id)
+    }
+
+    #[test]
+    fn test_milestone_partial_release_and_completion() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = register_client(&env);
+        let (token_addr, token_admin) = setup_token(&env);
+        let token = token::Client::new(&env, &token_addr);
+
+        let company = Address::generate(&env);
+        let developer = Address::generate(&env);
+        token_admin.mint(&company, &1_000);
+        token_admin.mint(&developer, &100);
+
+        client.register_developer(&developer, &vec![&env], &String::from_str(&env, "bio"));
+
+        let milestones = vec![
+            &env,
+            Milestone { description: String::from_str(&env, "m1"), amount: 600, submitted: false, released: false },
+            Milestone { description: String::from_str(&env, "m2"), amount: 400, submitted: false, released: false },
+        ];
+        let id = client.create_bounty(
+            &company,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "desc"),
+            &vec![&env],
+            &1_000,
+            &token_addr,
+            &0,
+            &milestones,
+        );
+
+        // Developer locks a commitment stake on assignment.
+        client.assign_bounty(&id, &developer, &100);
+        assert_eq!(token.balance(&developer), 0);
+
+        // First milestone: submit then approve releases only its amount.
+        client.submit_milestone(&id, &0, &developer);
+        client.approve_milestone(&id, &0, &company);
+        assert_eq!(token.balance(&developer), 600);
+        assert_eq!(client.get_bounty(&id).unwrap().status, BountyStatus::Assigned);
+
+        // Final milestone releases the remainder and refunds the stake.
+        client.submit_milestone(&id, &1, &developer);
+        client.approve_milestone(&id, &1, &company);
+        assert_eq!(token.balance(&developer), 1_100);
+        assert_eq!(client.get_bounty(&id).unwrap().status, BountyStatus::Completed);
+        assert_eq!(client.get_developer(&developer).unwrap().completed_bounties, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "sum")]
+    fn test_milestone_sum_invariant() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = register_client(&env);
+        let (token_addr, token_admin) = setup_token(&env);
+        let company = Address::generate(&env);
+        token_admin.mint(&company, &1_000);
+
+        let milestones = vec![
+            &env,
+            Milestone { description: String::from_str(&env, "m1"), amount: 600, submitted: false, released: false },
+            Milestone { description: String::from_str(&env, "m2"), amount: 300, submitted: false, released: false },
+        ];
+        client.create_bounty(
+            &company,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "desc"),
+            &vec![&env],
+            &1_000,
+            &token_addr,
+            &0,
+            &milestones,
+        );
+    }
+
+    #[test]
+    fn test_dispute_split_and_stake_refund() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = register_client(&env);
+        let (token_addr, token_admin) = setup_token(&env);
+        let token = token::Client::new(&env, &token_addr);
+
+        let admin = Address::generate(&env);
+        let arbitrator = Address::generate(&env);
+        let company = Address::generate(&env);
+        let developer = Address::generate(&env);
+        token_admin.mint(&company, &1_000);
+        token_admin.mint(&developer, &50);
+
+        client.initialize(&admin);
+        client.set_arbitrator(&arbitrator);
+
+        client.register_developer(&developer, &vec![&env], &String::from_str(&env, "bio"));
+
+        let id = client.create_bounty(
+            &company,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "desc"),
+            &vec![&env],
+            &1_000,
+            &token_addr,
+            &0,
+            &vec![&env],
+        );
+        client.assign_bounty(&id, &developer, &50);
+
+        // Dispute while assigned, then split 60/40 on the full escrow.
+        client.dispute_bounty(&id, &company);
+        client.resolve_dispute(&id, &arbitrator, &6_000);
+
+        // Developer: 600 share + 50 stake refund; company: 400 remainder.
+        assert_eq!(token.balance(&developer), 650);
+        assert_eq!(token.balance(&company), 400);
+        assert_eq!(client.get_bounty(&id).unwrap().status, BountyStatus::Completed);
+    }
 }
\ No newline at end of file